@@ -1,72 +1,123 @@
 extern crate proc_macro;
 use proc_macro::TokenStream;
 use convert_case::{Case, Casing};
+use darling::ast::{Data as DarlingData, Style};
+use darling::{FromDeriveInput, FromField, FromMeta};
 use quote::{format_ident, quote};
 use syn::{parse_macro_input, DeriveInput};
-use syn::{Data, DataStruct, Fields};
-use syn::parse::{Parse, ParseStream, Result};
-use syn::{Ident, Token};
-use syn::spanned::Spanned;
-
-/// Parser for attribute arguments.
-struct CreteArgs {
-    clone: bool
+use syn::Ident;
+
+/// Identifies a struct field independently of whether the struct uses named
+/// fields (`foo: Type`) or positional ones (`Type` in a tuple struct), so the
+/// rest of the macro can treat both the same way.
+enum FieldKey {
+    Named(Ident),
+    Index(syn::Index),
 }
 
-impl Parse for CreteArgs {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let mut clone = false;
+impl quote::ToTokens for FieldKey {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            FieldKey::Named(ident) => ident.to_tokens(tokens),
+            FieldKey::Index(index) => index.to_tokens(tokens),
+        }
+    }
+}
 
-        // If the input is empty, no attributes were provided.
-        if input.is_empty() {
-            return Ok(CreteArgs { clone: false });
+impl FieldKey {
+    /// The name of the generated `Field` unit struct, e.g. `CountField` for a
+    /// named field `count`, or `Field0` for the first field of a tuple struct.
+    fn unit_struct_name(&self) -> Ident {
+        match self {
+            FieldKey::Named(ident) => format_ident!("{}Field", ident.to_string().to_case(Case::Pascal)),
+            FieldKey::Index(index) => format_ident!("Field{}", index.index),
         }
+    }
+}
 
-        // Parse comma-separated identifiers.
-        while !input.is_empty() {
-            let ident: Ident = input.parse()?;
-
-            if ident == "Clone" {
-                if clone {
-                    // Error if "Clone" is specified more than once.
-                    return Err(syn::Error::new(
-                        ident.span(),
-                        "Duplicate 'Clone' attribute",
-                    ));
-                }
-                clone = true;
-            } else {
-                return Err(syn::Error::new(
-                    ident.span(),
-                    format!("Unexpected attribute '{}'. Expected 'Clone'.", ident),
-                ));
-            }
+/// The arguments to the `#[crete(...)]` attribute itself, e.g. `#[crete(Clone, persist)]`.
+#[derive(Debug, Default, FromMeta)]
+#[darling(default)]
+struct CreteArgs {
+    #[darling(rename = "Clone")]
+    clone: bool,
+    /// Generate `snapshot`/`restore`/`save_to`/`load_from`. Requires the struct to be
+    /// `Serialize + DeserializeOwned`.
+    persist: bool,
+    /// `instances(Primary, Secondary)`: give the struct one independent store per named
+    /// instance instead of a single process-global one. Leave empty to get a single default
+    /// `Global` instance (`on::<Global>()`) alongside the existing, backward-compatible
+    /// single-global API.
+    #[darling(default)]
+    instances: darling::util::PathList,
+}
 
-            // If there's more input, expect a comma.
-            if !input.is_empty() {
-                let comma: Token![,] = input.parse()?;
-                if input.is_empty() {
-                    return Err(syn::Error::new(
-                        comma.span(),
-                        "Trailing comma not allowed",
-                    ));
-                }
-            }
-        }
+/// Per-field `#[crete(...)]` attributes.
+#[derive(Debug, FromField)]
+#[darling(attributes(crete))]
+struct CreteFieldReceiver {
+    ident: Option<Ident>,
+    ty: syn::Type,
+    /// Don't generate a `Field` unit struct or accessor for this field.
+    #[darling(default)]
+    skip: bool,
+    /// Override the generated `XxxField` unit-struct name.
+    #[darling(default)]
+    rename: Option<String>,
+    /// Generate `select`/`get` but not `set` for this field.
+    #[darling(default)]
+    readonly: bool,
+    /// Expression used by `new()` for this field instead of `Default::default()`.
+    #[darling(default)]
+    default: Option<String>,
+    /// Path to a `fn(&FieldType) -> Result<(), E>` validator invoked by `set` and by the
+    /// transaction handle's `set` before the value is applied; the commit itself does not
+    /// re-run it. `E` just needs `Into<Box<dyn std::error::Error + Send + Sync>>` --
+    /// `String` and anything implementing `std::error::Error + Send + Sync` both work.
+    #[darling(default)]
+    guard: Option<String>,
+}
 
-        Ok(CreteArgs { clone })
-    }
+/// Receiver for the struct being annotated, used only to collect the
+/// per-field `#[crete(...)]` attributes via darling.
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(crete))]
+struct CreteStructReceiver {
+    data: DarlingData<darling::util::Ignored, CreteFieldReceiver>,
 }
 
 #[proc_macro_attribute]
 pub fn crete(attr: TokenStream, item: TokenStream) -> TokenStream {
-    // Parse attribute parameters using our custom parser.
-    let args = parse_macro_input!(attr as CreteArgs);
+    // Parse `#[crete(...)]`'s own arguments (e.g. `Clone`) via darling.
+    let attr_meta = match darling::ast::NestedMeta::parse_meta_list(attr.into()) {
+        Ok(meta) => meta,
+        Err(err) => return TokenStream::from(darling::Error::from(err).write_errors()),
+    };
+    let args = match CreteArgs::from_list(&attr_meta) {
+        Ok(args) => args,
+        Err(err) => return TokenStream::from(err.write_errors()),
+    };
 
     // Parse the struct definition.
-    let input = parse_macro_input!(item as DeriveInput);
+    let mut input = parse_macro_input!(item as DeriveInput);
     let struct_name = &input.ident;
 
+    // Parse the struct's own `#[crete(...)]` field attributes (skip, rename, readonly, default).
+    let struct_receiver = match CreteStructReceiver::from_derive_input(&input) {
+        Ok(receiver) => receiver,
+        Err(err) => return TokenStream::from(err.write_errors()),
+    };
+
+    // `crete` is an attribute macro, not a derive, so nothing strips the field-level
+    // `#[crete(...)]` attributes darling just read above -- left in place, they'd be emitted
+    // verbatim on `#input` below and the compiler would reject them as an unknown attribute
+    // macro on a field. Strip them here before `#input` is ever quoted back out.
+    if let syn::Data::Struct(syn::DataStruct { fields, .. }) = &mut input.data {
+        for field in fields.iter_mut() {
+            field.attrs.retain(|attr| !attr.path().is_ident("crete"));
+        }
+    }
+
     // Check if the struct derives Clone.
     let derives_clone = input.attrs.iter().any(|attr| {
         if attr.path().is_ident("derive") {
@@ -88,54 +139,139 @@ pub fn crete(attr: TokenStream, item: TokenStream) -> TokenStream {
     let struct_is_clone = args.clone || derives_clone;
     // println!("Struct name: {}, Struct is clone: {}, args.clone: {}, impl Clone: {}", struct_name, struct_is_clone, args.clone, derives_clone);
 
-    // Extract the fields (only works with named fields).
-    let fields = if let Data::Struct(DataStruct {
-                                         fields: Fields::Named(ref fields_named),
-                                         ..
-                                     }) = input.data
-    {
-        &fields_named.named
-    } else {
-        panic!("Crete can only be used with named fields");
+    // Unify named, tuple and unit structs behind `FieldKey`, keeping every field (including
+    // `skip`ped ones) so `new()` still applies their `#[crete(default = "...")]` overrides.
+    let darling::ast::Fields { style, fields: field_receivers, .. } = match struct_receiver.data {
+        DarlingData::Struct(fields) => fields,
+        DarlingData::Enum(_) => panic!("Crete can only be used with structs"),
     };
 
-    // Define the Field trait. Note the store parameter is of type `#struct_name` (e.g. Store)
+    let all_fields: Vec<(FieldKey, &CreteFieldReceiver)> = field_receivers
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let key = match style {
+                Style::Tuple => FieldKey::Index(syn::Index::from(index)),
+                _ => FieldKey::Named(field.ident.clone().expect("named field without ident")),
+            };
+            (key, field)
+        })
+        .collect();
+
+    // The fields that get a `Field` unit struct and accessor; `skip` hides a field entirely.
+    let accessor_fields: Vec<(&FieldKey, &CreteFieldReceiver)> = all_fields
+        .iter()
+        .filter(|(_, field)| !field.skip)
+        .map(|(key, field)| (key, *field))
+        .collect();
+
+    // Define the Field trait. Note the store parameter is of type `#struct_name` (e.g. Store).
+    // `SettableField` is split out so `#[crete(readonly)]` fields can implement `Field` (for
+    // `select`/`get`) without exposing `set` at all -- calling it becomes a compile error.
+    // `validate` defaults to a no-op so `#[crete(guard = "...")]` only costs anything on the
+    // fields that opt into it; it's type-erased since each field's validator can fail with a
+    // different error type.
     let field_trait = quote! {
         pub trait Field {
             type FieldType;
             fn select<'a>(&self, store: &'a #struct_name) -> &'a Self::FieldType;
+        }
+
+        pub trait SettableField: Field {
+            fn validate(&self, _value: &Self::FieldType) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+                Ok(())
+            }
             fn set(&self, store: &mut #struct_name, value: Self::FieldType);
         }
     };
 
-    // Generate unit structs and their implementations for each field.
-    let unit_structs = fields.iter().map(|field| {
-        let ident = field.ident.as_ref().unwrap();
-        let unit_struct_name = format_ident!("{}Field", ident.to_string().to_case(Case::Pascal));
+    // Generate unit structs and their implementations for each non-skipped field.
+    // `Clone, Copy` let the marker type be carried across the `await` points
+    // in `watch` without the caller needing to re-name the field at each step.
+    let unit_structs = accessor_fields.iter().map(|(key, field)| {
+        let unit_struct_name = match &field.rename {
+            Some(name) => format_ident!("{}", name),
+            None => key.unit_struct_name(),
+        };
         let ty = &field.ty;
+
+        let settable_impl = if field.readonly {
+            quote! {}
+        } else {
+            // Like `skip`/`rename`/`readonly`/`default`, `guard` is a field-level `#[crete(...)]`
+            // attribute that darling reads here and that gets stripped from the emitted struct
+            // before `#input` is quoted back out -- see the attribute-stripping pass above.
+            let validate_impl = match &field.guard {
+                Some(guard_path) => {
+                    let guard_path: proc_macro2::TokenStream = guard_path
+                        .parse()
+                        .unwrap_or_else(|_| panic!("invalid `guard` path: {}", guard_path));
+                    quote! {
+                        fn validate(&self, value: &Self::FieldType) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+                            (#guard_path)(value).map_err(|err| -> Box<dyn std::error::Error + Send + Sync> { err.into() })
+                        }
+                    }
+                }
+                None => quote! {},
+            };
+
+            quote! {
+                impl SettableField for #unit_struct_name {
+                    #validate_impl
+
+                    fn set(&self, store: &mut #struct_name, value: Self::FieldType) {
+                        store.#key = value;
+                    }
+                }
+            }
+        };
+
         quote! {
+            #[derive(Clone, Copy)]
             pub struct #unit_struct_name;
 
             impl Field for #unit_struct_name {
                 type FieldType = #ty;
                 fn select<'a>(&self, store: &'a #struct_name) -> &'a Self::FieldType {
-                    &store.#ident
-                }
-                fn set(&self, store: &mut #struct_name, value: Self::FieldType) {
-                    store.#ident = value;
+                    &store.#key
                 }
             }
+
+            #settable_impl
         }
     });
 
+    // `new()`'s body: starts from `Self::default()` (so a hand-written `impl Default` is still
+    // respected) and only overrides the fields carrying an explicit `#[crete(default = "...")]`.
+    let field_overrides = all_fields.iter().filter_map(|(key, field)| {
+        let expr = field.default.as_ref()?;
+        let expr: proc_macro2::TokenStream = expr
+            .parse()
+            .unwrap_or_else(|_| panic!("invalid `default` expression for field: {}", expr));
+
+        Some(match key {
+            FieldKey::Named(ident) => quote! { instance.#ident = #expr; },
+            FieldKey::Index(index) => quote! { instance.#index = #expr; },
+        })
+    });
+
+    let new_body = quote! {
+        #[allow(unused_mut)]
+        let mut instance = #struct_name::default();
+        #(#field_overrides)*
+        instance
+    };
+
     // Create the static store identifier.
     let crete_store_ident = format_ident!("CRETE_{}", struct_name.to_string().to_uppercase());
+    // Broadcast bus used to notify `watch` subscribers after a mutation lands.
+    let crete_tx_ident = format_ident!("{}_TX", crete_store_ident);
 
     let impl_block = if struct_is_clone {
         quote! {
             impl #struct_name {
                 pub fn new() -> Self {
-                    #struct_name::default()
+                    #new_body
                 }
 
                 pub fn read() -> Arc<#struct_name> {
@@ -149,6 +285,7 @@ pub fn crete(attr: TokenStream, item: TokenStream) -> TokenStream {
 
                 pub fn write(self) {
                     *#crete_store_ident.write().expect("RWLock poisoned") = Arc::new(self);
+                    let _ = #crete_tx_ident.send(#struct_name::read());
                 }
 
                 pub fn select_ref<F: Field>(&self, field: F) -> &F::FieldType {
@@ -171,31 +308,81 @@ pub fn crete(attr: TokenStream, item: TokenStream) -> TokenStream {
                     #struct_name::read().select_ref(field).clone()
                 }
 
-                pub fn set<F>(field: F, value: F::FieldType)
+                pub fn set<F>(field: F, value: F::FieldType) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
                 where
-                    F: Field,
+                    F: SettableField,
                 {
-                    let mut store_write_guard = #crete_store_ident.write().expect("RWLock poisoned");
-                    let mut s = Arc::make_mut(&mut *store_write_guard);
-
-                    field.set(&mut s, value);
+                    field.validate(&value)?;
+                    {
+                        let mut store_write_guard = #crete_store_ident.write().expect("RWLock poisoned");
+                        let mut s = Arc::make_mut(&mut *store_write_guard);
+
+                        field.set(&mut s, value);
+                    }
+                    let _ = #crete_tx_ident.send(#struct_name::read());
+                    Ok(())
                 }
 
                 pub fn update(f: impl FnOnce(&mut #struct_name) -> ()) {
-                    let mut store_write_guard = #crete_store_ident.write().expect("RWLock poisoned");
-                    let s = Arc::make_mut(&mut *store_write_guard);
+                    {
+                        let mut store_write_guard = #crete_store_ident.write().expect("RWLock poisoned");
+                        let s = Arc::make_mut(&mut *store_write_guard);
 
-                    f(s);
+                        f(s);
+                    }
+                    let _ = #crete_tx_ident.send(#struct_name::read());
                 }
 
                 pub async fn update_async<F>(f: F)
                 where
                     F: AsyncFnOnce(&mut #struct_name),
                 {
-                    let mut store_write_guard = #crete_store_ident.write().expect("RWLock poisoned");
-                    let s = Arc::make_mut(&mut *store_write_guard);
+                    {
+                        let mut store_write_guard = #crete_store_ident.write().expect("RWLock poisoned");
+                        let s = Arc::make_mut(&mut *store_write_guard);
+
+                        f(s).await;
+                    }
+                    let _ = #crete_tx_ident.send(#struct_name::read());
+                }
 
-                    f(s).await;
+                /// Subscribes to changes of a single field, yielding a new value each time
+                /// it actually changes (consecutive equal values are collapsed). The current
+                /// value is delivered immediately so late subscribers aren't starved.
+                pub fn watch<F>(field: F) -> std::pin::Pin<Box<dyn futures::Stream<Item = F::FieldType>>>
+                where
+                    F: Field + Clone + 'static,
+                    F::FieldType: Clone + PartialEq,
+                {
+                    let rx = #crete_tx_ident.subscribe();
+                    let initial = #struct_name::read();
+
+                    Box::pin(futures::stream::unfold(
+                        (rx, field, None::<F::FieldType>, Some(initial)),
+                        |(mut rx, field, mut last, mut pending)| async move {
+                            loop {
+                                let snapshot = match pending.take() {
+                                    Some(snapshot) => snapshot,
+                                    None => match rx.recv().await {
+                                        Ok(snapshot) => snapshot,
+                                        // We missed one or more updates; re-read rather than
+                                        // ending the stream, so a lagging subscriber resyncs to
+                                        // the current value instead of going silent.
+                                        Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
+                                            #struct_name::read()
+                                        }
+                                        Err(tokio::sync::broadcast::error::RecvError::Closed) => return None,
+                                    },
+                                };
+
+                                let value = field.select(&snapshot).clone();
+                                if last.as_ref() != Some(&value) {
+                                    last = Some(value.clone());
+                                    return Some((value, (rx, field, last, None)));
+                                }
+                            }
+                        },
+                    ))
                 }
             }
         }
@@ -203,13 +390,16 @@ pub fn crete(attr: TokenStream, item: TokenStream) -> TokenStream {
         quote! {
             impl #struct_name {
                 pub fn new() -> Self {
-                    #struct_name::default()
+                    #new_body
                 }
 
                 pub fn write(self) {
-                    let store_arc = #crete_store_ident.clone();
-                    let mut store_guard = store_arc.write().expect("RWLock poisoned");
-                    *store_guard = self;
+                    {
+                        let store_arc = #crete_store_ident.clone();
+                        let mut store_guard = store_arc.write().expect("RWLock poisoned");
+                        *store_guard = self;
+                    }
+                    let _ = #crete_tx_ident.send(());
                 }
 
                 pub fn select_ref<F: Field>(&self, field: F) -> &F::FieldType {
@@ -227,31 +417,87 @@ pub fn crete(attr: TokenStream, item: TokenStream) -> TokenStream {
                     f(field_ref)
                 }
 
-                pub fn set<F>(field: F, value: F::FieldType)
+                pub fn set<F>(field: F, value: F::FieldType) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
                 where
-                    F: Field,
+                    F: SettableField,
                 {
-                    let store_arc = #crete_store_ident.clone();
-                    let mut store_guard = store_arc.write().expect("RWLock poisoned");
-
-                    field.set(&mut *store_guard, value);
+                    field.validate(&value)?;
+                    {
+                        let store_arc = #crete_store_ident.clone();
+                        let mut store_guard = store_arc.write().expect("RWLock poisoned");
+
+                        field.set(&mut *store_guard, value);
+                    }
+                    let _ = #crete_tx_ident.send(());
+                    Ok(())
                 }
 
                 pub fn update(f: impl FnOnce(&mut #struct_name) -> ()) {
-                    let store_arc = #crete_store_ident.clone();
-                    let mut store_guard = store_arc.write().expect("RWLock poisoned");
+                    {
+                        let store_arc = #crete_store_ident.clone();
+                        let mut store_guard = store_arc.write().expect("RWLock poisoned");
 
-                    f(&mut *store_guard);
+                        f(&mut *store_guard);
+                    }
+                    let _ = #crete_tx_ident.send(());
                 }
 
                 pub async fn update_async<F>(f: F)
                 where
                     F: AsyncFnOnce(&mut #struct_name),
                 {
-                    let store_arc = #crete_store_ident.clone();
-                    let mut store_guard = store_arc.write().expect("RWLock poisoned");
+                    {
+                        let store_arc = #crete_store_ident.clone();
+                        let mut store_guard = store_arc.write().expect("RWLock poisoned");
+
+                        f(&mut *store_guard).await;
+                    }
+                    let _ = #crete_tx_ident.send(());
+                }
 
-                    f(&mut *store_guard).await;
+                /// Subscribes to changes of a single field, yielding a new value each time
+                /// it actually changes (consecutive equal values are collapsed). The current
+                /// value is delivered immediately so late subscribers aren't starved.
+                ///
+                /// Unlike the `Clone` variant, the store itself isn't `Clone`, so the bus only
+                /// carries a change ping; each tick re-reads the field under a fresh lock.
+                pub fn watch<F>(field: F) -> std::pin::Pin<Box<dyn futures::Stream<Item = F::FieldType>>>
+                where
+                    F: Field + Clone + 'static,
+                    F::FieldType: Clone + PartialEq,
+                {
+                    let rx = #crete_tx_ident.subscribe();
+
+                    Box::pin(futures::stream::unfold(
+                        (rx, field, None::<F::FieldType>, true),
+                        |(mut rx, field, mut last, mut first)| async move {
+                            loop {
+                                if !first {
+                                    match rx.recv().await {
+                                        Ok(()) => {}
+                                        // We missed one or more pings; re-read rather than
+                                        // ending the stream, so a lagging subscriber resyncs
+                                        // to the current value instead of going silent.
+                                        Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {}
+                                        Err(tokio::sync::broadcast::error::RecvError::Closed) => return None,
+                                    }
+                                }
+                                first = false;
+
+                                // `field` would otherwise be moved into `get` and reused below;
+                                // read it directly under a fresh lock instead.
+                                let store_arc = #crete_store_ident.clone();
+                                let value = {
+                                    let store_guard = store_arc.read().expect("RWLock poisoned");
+                                    field.select(&*store_guard).clone()
+                                };
+                                if last.as_ref() != Some(&value) {
+                                    last = Some(value.clone());
+                                    return Some((value, (rx, field, last, false)));
+                                }
+                            }
+                        },
+                    ))
                 }
             }
         }
@@ -269,6 +515,390 @@ pub fn crete(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     };
 
+    // The watch bus: a snapshot per change for the `Clone` variant, a bare ping otherwise.
+    let static_tx = if struct_is_clone {
+        quote! {
+            static #crete_tx_ident: LazyLock<tokio::sync::broadcast::Sender<Arc<#struct_name>>> =
+                LazyLock::new(|| tokio::sync::broadcast::channel(16).0);
+        }
+    } else {
+        quote! {
+            static #crete_tx_ident: LazyLock<tokio::sync::broadcast::Sender<()>> =
+                LazyLock::new(|| tokio::sync::broadcast::channel(16).0);
+        }
+    };
+
+    // Opt-in persistence: JSON snapshot/restore for the whole store, gated by
+    // `#[crete(persist)]` since it requires the struct to be Serialize + DeserializeOwned.
+    let persist_impl = if args.persist {
+        let snapshot_and_restore = if struct_is_clone {
+            quote! {
+                pub fn snapshot() -> String {
+                    serde_json::to_string(&*#struct_name::read()).expect("failed to serialize store")
+                }
+
+                pub fn restore(data: &str) -> Result<(), serde_json::Error> {
+                    let value: #struct_name = serde_json::from_str(data)?;
+                    *#crete_store_ident.write().expect("RWLock poisoned") = Arc::new(value);
+                    let _ = #crete_tx_ident.send(#struct_name::read());
+                    Ok(())
+                }
+            }
+        } else {
+            quote! {
+                pub fn snapshot() -> String {
+                    let store_arc = #crete_store_ident.clone();
+                    let store_guard = store_arc.read().expect("RWLock poisoned");
+                    serde_json::to_string(&*store_guard).expect("failed to serialize store")
+                }
+
+                pub fn restore(data: &str) -> Result<(), serde_json::Error> {
+                    let value: #struct_name = serde_json::from_str(data)?;
+                    {
+                        let store_arc = #crete_store_ident.clone();
+                        let mut store_guard = store_arc.write().expect("RWLock poisoned");
+                        *store_guard = value;
+                    }
+                    let _ = #crete_tx_ident.send(());
+                    Ok(())
+                }
+            }
+        };
+
+        quote! {
+            impl #struct_name {
+                #snapshot_and_restore
+
+                pub fn save_to(path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+                    std::fs::write(path, #struct_name::snapshot())
+                }
+
+                pub fn load_from(path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+                    let data = std::fs::read_to_string(path)?;
+                    #struct_name::restore(&data)
+                        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Atomic multi-field transactions: mutations accumulate on an owned working copy, and only
+    // land in the store (under a single write-lock acquisition) once the guard closure returns
+    // `Ok`. This needs an owned copy of the struct to stage mutations against, so it's only
+    // generated for the `Clone` variant.
+    let transaction_impl = if struct_is_clone {
+        let txn_ident = format_ident!("{}Txn", struct_name);
+        quote! {
+            pub struct #txn_ident {
+                working: #struct_name,
+            }
+
+            impl #txn_ident {
+                pub fn select_ref<F: Field>(&self, field: F) -> &F::FieldType {
+                    field.select(&self.working)
+                }
+
+                pub fn select<F: Field>(&self, field: F) -> F::FieldType
+                where
+                    F::FieldType: Clone,
+                {
+                    self.select_ref(field).clone()
+                }
+
+                pub fn set<F>(&mut self, field: F, value: F::FieldType) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+                where
+                    F: SettableField,
+                {
+                    field.validate(&value)?;
+                    field.set(&mut self.working, value);
+                    Ok(())
+                }
+            }
+
+            impl #struct_name {
+                pub fn transaction<R, E>(f: impl FnOnce(&mut #txn_ident) -> Result<R, E>) -> Result<R, E> {
+                    let mut txn = #txn_ident { working: #struct_name::clone() };
+                    let result = f(&mut txn)?;
+
+                    {
+                        let mut store_write_guard = #crete_store_ident.write().expect("RWLock poisoned");
+                        *store_write_guard = Arc::new(txn.working);
+                    }
+                    let _ = #crete_tx_ident.send(#struct_name::read());
+
+                    Ok(result)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Multiple named store instances (`#[crete(instances(Primary, Secondary))]`): each instance
+    // gets its own static + marker type, and `#struct_name::on::<Primary>()` returns a handle
+    // parameterized over that marker so the same accessor surface works per-instance. Leaving
+    // `instances` empty still gets you `on::<Global>()`, but `Global` is aliased directly to the
+    // `#crete_store_ident`/`#crete_tx_ident` statics above rather than a separate copy, so it and
+    // the bare `Struct::...` API always observe the same state.
+    let instances_impl = {
+        let instance_trait_ident = format_ident!("{}Instance", struct_name);
+        let handle_ident = format_ident!("{}Handle", struct_name);
+
+        let (store_ty, tx_ty) = if struct_is_clone {
+            (quote! { RwLock<Arc<#struct_name>> }, quote! { tokio::sync::broadcast::Sender<Arc<#struct_name>> })
+        } else {
+            (quote! { Arc<RwLock<#struct_name>> }, quote! { tokio::sync::broadcast::Sender<()> })
+        };
+
+        let instance_defs = if args.instances.is_empty() {
+            quote! {
+                pub struct Global;
+
+                impl #instance_trait_ident for Global {
+                    fn store() -> &'static LazyLock<#store_ty> { &#crete_store_ident }
+                    fn tx() -> &'static LazyLock<#tx_ty> { &#crete_tx_ident }
+                }
+            }
+        } else {
+            let defs = args.instances.iter().map(|instance_path| {
+                let instance_name = instance_path.segments.last().unwrap().ident.to_string().to_uppercase();
+                let instance_store_ident = format_ident!("{}_{}", crete_store_ident, instance_name);
+                let instance_tx_ident = format_ident!("{}_TX", instance_store_ident);
+
+                let store_init = if struct_is_clone {
+                    quote! { RwLock::new(Arc::new(#struct_name::new())) }
+                } else {
+                    quote! { Arc::new(RwLock::new(#struct_name::new())) }
+                };
+
+                quote! {
+                    pub struct #instance_path;
+
+                    static #instance_store_ident: LazyLock<#store_ty> = LazyLock::new(|| #store_init);
+                    static #instance_tx_ident: LazyLock<#tx_ty> = LazyLock::new(|| tokio::sync::broadcast::channel(16).0);
+
+                    impl #instance_trait_ident for #instance_path {
+                        fn store() -> &'static LazyLock<#store_ty> { &#instance_store_ident }
+                        fn tx() -> &'static LazyLock<#tx_ty> { &#instance_tx_ident }
+                    }
+                }
+            });
+
+            quote! { #(#defs)* }
+        };
+
+        let handle_impl = if struct_is_clone {
+            quote! {
+                impl<I: #instance_trait_ident> #handle_ident<I> {
+                    pub fn read(&self) -> Arc<#struct_name> {
+                        I::store().read().expect("RWLock poisoned").clone()
+                    }
+
+                    pub fn write(&self, value: #struct_name) {
+                        *I::store().write().expect("RWLock poisoned") = Arc::new(value);
+                        let _ = I::tx().send(I::store().read().expect("RWLock poisoned").clone());
+                    }
+
+                    pub fn get<F, R>(&self, field: F, f: impl FnOnce(&F::FieldType) -> R) -> R
+                    where
+                        F: Field,
+                    {
+                        let store = self.read();
+                        f(field.select(&store))
+                    }
+
+                    pub fn select<F: Field>(&self, field: F) -> F::FieldType
+                    where
+                        F::FieldType: Clone,
+                    {
+                        field.select(&self.read()).clone()
+                    }
+
+                    pub fn set<F>(&self, field: F, value: F::FieldType) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+                    where
+                        F: SettableField,
+                    {
+                        field.validate(&value)?;
+                        {
+                            let mut store_write_guard = I::store().write().expect("RWLock poisoned");
+                            let s = Arc::make_mut(&mut *store_write_guard);
+                            field.set(s, value);
+                        }
+                        let _ = I::tx().send(self.read());
+                        Ok(())
+                    }
+
+                    pub fn update(&self, f: impl FnOnce(&mut #struct_name)) {
+                        {
+                            let mut store_write_guard = I::store().write().expect("RWLock poisoned");
+                            let s = Arc::make_mut(&mut *store_write_guard);
+                            f(s);
+                        }
+                        let _ = I::tx().send(self.read());
+                    }
+
+                    pub async fn update_async<F>(&self, f: F)
+                    where
+                        F: AsyncFnOnce(&mut #struct_name),
+                    {
+                        {
+                            let mut store_write_guard = I::store().write().expect("RWLock poisoned");
+                            let s = Arc::make_mut(&mut *store_write_guard);
+                            f(s).await;
+                        }
+                        let _ = I::tx().send(self.read());
+                    }
+
+                    pub fn watch<F>(&self, field: F) -> std::pin::Pin<Box<dyn futures::Stream<Item = F::FieldType>>>
+                    where
+                        F: Field + Clone + 'static,
+                        F::FieldType: Clone + PartialEq,
+                    {
+                        let rx = I::tx().subscribe();
+                        let initial = self.read();
+
+                        Box::pin(futures::stream::unfold(
+                            (rx, field, None::<F::FieldType>, Some(initial)),
+                            |(mut rx, field, mut last, mut pending)| async move {
+                                loop {
+                                    let snapshot = match pending.take() {
+                                        Some(snapshot) => snapshot,
+                                        None => match rx.recv().await {
+                                            Ok(snapshot) => snapshot,
+                                            // We missed one or more updates; re-read rather than
+                                            // ending the stream, so a lagging subscriber resyncs
+                                            // to the current value instead of going silent.
+                                            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
+                                                I::store().read().expect("RWLock poisoned").clone()
+                                            }
+                                            Err(tokio::sync::broadcast::error::RecvError::Closed) => return None,
+                                        },
+                                    };
+
+                                    let value = field.select(&snapshot).clone();
+                                    if last.as_ref() != Some(&value) {
+                                        last = Some(value.clone());
+                                        return Some((value, (rx, field, last, None)));
+                                    }
+                                }
+                            },
+                        ))
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl<I: #instance_trait_ident> #handle_ident<I> {
+                    pub fn write(&self, value: #struct_name) {
+                        {
+                            let mut store_guard = I::store().write().expect("RWLock poisoned");
+                            *store_guard = value;
+                        }
+                        let _ = I::tx().send(());
+                    }
+
+                    pub fn get<F, R>(&self, field: F, f: impl FnOnce(&F::FieldType) -> R) -> R
+                    where
+                        F: Field,
+                    {
+                        let store_guard = I::store().read().expect("RWLock poisoned");
+                        f(field.select(&*store_guard))
+                    }
+
+                    pub fn set<F>(&self, field: F, value: F::FieldType) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+                    where
+                        F: SettableField,
+                    {
+                        field.validate(&value)?;
+                        {
+                            let mut store_guard = I::store().write().expect("RWLock poisoned");
+                            field.set(&mut *store_guard, value);
+                        }
+                        let _ = I::tx().send(());
+                        Ok(())
+                    }
+
+                    pub fn update(&self, f: impl FnOnce(&mut #struct_name)) {
+                        {
+                            let mut store_guard = I::store().write().expect("RWLock poisoned");
+                            f(&mut *store_guard);
+                        }
+                        let _ = I::tx().send(());
+                    }
+
+                    pub async fn update_async<F>(&self, f: F)
+                    where
+                        F: AsyncFnOnce(&mut #struct_name),
+                    {
+                        {
+                            let mut store_guard = I::store().write().expect("RWLock poisoned");
+                            f(&mut *store_guard).await;
+                        }
+                        let _ = I::tx().send(());
+                    }
+
+                    pub fn watch<F>(&self, field: F) -> std::pin::Pin<Box<dyn futures::Stream<Item = F::FieldType>>>
+                    where
+                        F: Field + Clone + 'static,
+                        F::FieldType: Clone + PartialEq,
+                    {
+                        let rx = I::tx().subscribe();
+                        let store = I::store();
+
+                        Box::pin(futures::stream::unfold(
+                            (rx, field, None::<F::FieldType>, true),
+                            move |(mut rx, field, mut last, mut first)| async move {
+                                loop {
+                                    if !first {
+                                        match rx.recv().await {
+                                            Ok(()) => {}
+                                            // We missed one or more pings; re-read rather than
+                                            // ending the stream, so a lagging subscriber resyncs
+                                            // to the current value instead of going silent.
+                                            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {}
+                                            Err(tokio::sync::broadcast::error::RecvError::Closed) => return None,
+                                        }
+                                    }
+                                    first = false;
+
+                                    let value = {
+                                        let store_guard = store.read().expect("RWLock poisoned");
+                                        field.select(&*store_guard).clone()
+                                    };
+                                    if last.as_ref() != Some(&value) {
+                                        last = Some(value.clone());
+                                        return Some((value, (rx, field, last, false)));
+                                    }
+                                }
+                            },
+                        ))
+                    }
+                }
+            }
+        };
+
+        quote! {
+            pub trait #instance_trait_ident: 'static {
+                fn store() -> &'static LazyLock<#store_ty>;
+                fn tx() -> &'static LazyLock<#tx_ty>;
+            }
+
+            pub struct #handle_ident<I>(std::marker::PhantomData<I>);
+
+            impl #struct_name {
+                pub fn on<I: #instance_trait_ident>() -> #handle_ident<I> {
+                    #handle_ident(std::marker::PhantomData)
+                }
+            }
+
+            #instance_defs
+
+            #handle_impl
+        }
+    };
+
     let expanded = quote! {
         use std::sync::{Arc, RwLock, LazyLock};
 
@@ -280,7 +910,15 @@ pub fn crete(attr: TokenStream, item: TokenStream) -> TokenStream {
 
         #static_store
 
+        #static_tx
+
         #impl_block
+
+        #persist_impl
+
+        #transaction_impl
+
+        #instances_impl
     };
 
     TokenStream::from(expanded)
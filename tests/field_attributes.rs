@@ -0,0 +1,38 @@
+use crete::crete;
+
+#[crete]
+#[derive(Clone, Default)]
+struct Settings {
+    name: String,
+    #[crete(skip)]
+    cache: Vec<u8>,
+    #[crete(rename = "Vol")]
+    volume: u8,
+    #[crete(readonly)]
+    version: u32,
+    #[crete(default = "42")]
+    answer: i32,
+}
+
+#[test]
+fn rename_overrides_the_generated_field_unit_struct_name() {
+    Settings::default().write();
+    Settings::set(Vol, 5).unwrap();
+    assert_eq!(Settings::select(Vol), 5);
+}
+
+#[test]
+fn default_overrides_new_for_that_field_only() {
+    let settings = Settings::new();
+    assert_eq!(settings.answer, 42);
+    assert_eq!(settings.name, String::default());
+}
+
+#[test]
+fn readonly_fields_are_still_selectable() {
+    Settings::default().write();
+    assert_eq!(Settings::select(VersionField), 0);
+}
+
+// `cache` is `skip`ped, so it never gets a `Field` unit struct or accessor; referencing a
+// `CacheField` here would be a compile error, so no test in this file references it.
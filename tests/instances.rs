@@ -0,0 +1,39 @@
+mod named_instances {
+    use crete::crete;
+
+    #[crete(instances(Primary, Secondary))]
+    #[derive(Clone, Default)]
+    struct Account {
+        balance: i64,
+    }
+
+    #[test]
+    fn named_instances_keep_independent_state() {
+        Account::on::<Primary>().write(Account { balance: 100 });
+        Account::on::<Secondary>().write(Account { balance: 5 });
+
+        assert_eq!(Account::on::<Primary>().select(BalanceField), 100);
+        assert_eq!(Account::on::<Secondary>().select(BalanceField), 5);
+    }
+}
+
+mod default_instance {
+    use crete::crete;
+
+    #[crete]
+    #[derive(Clone, Default)]
+    struct Widget {
+        count: i32,
+    }
+
+    #[test]
+    fn on_global_is_aliased_to_the_single_global_store() {
+        // `on::<Global>()` and the bare `Widget::...` API observe the same state --
+        // `Global` isn't a separate copy of the store.
+        Widget::on::<Global>().write(Widget { count: 9 });
+        assert_eq!(Widget::select(CountField), 9);
+
+        Widget { count: 3 }.write();
+        assert_eq!(Widget::on::<Global>().select(CountField), 3);
+    }
+}
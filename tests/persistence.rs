@@ -0,0 +1,49 @@
+mod snapshot_and_restore {
+    use crete::crete;
+
+    #[crete(persist)]
+    #[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+    struct Profile {
+        name: String,
+        level: u32,
+    }
+
+    #[test]
+    fn snapshot_and_restore_round_trip() {
+        Profile { name: "ada".into(), level: 3 }.write();
+        let data = Profile::snapshot();
+
+        Profile::default().write();
+        assert_eq!(Profile::read().level, 0);
+
+        Profile::restore(&data).unwrap();
+        assert_eq!(Profile::read().level, 3);
+        assert_eq!(Profile::read().name, "ada");
+    }
+}
+
+mod save_to_and_load_from {
+    use crete::crete;
+
+    #[crete(persist)]
+    #[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+    struct Profile {
+        name: String,
+        level: u32,
+    }
+
+    #[test]
+    fn save_to_and_load_from_round_trip_through_a_file() {
+        let path = std::env::temp_dir().join("crete_persistence_test_profile.json");
+
+        Profile { name: "grace".into(), level: 7 }.write();
+        Profile::save_to(&path).unwrap();
+
+        Profile::default().write();
+        Profile::load_from(&path).unwrap();
+        assert_eq!(Profile::read().level, 7);
+        assert_eq!(Profile::read().name, "grace");
+
+        std::fs::remove_file(&path).ok();
+    }
+}
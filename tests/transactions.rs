@@ -0,0 +1,54 @@
+use crete::crete;
+
+fn non_negative(balance: &i64) -> Result<(), String> {
+    if *balance < 0 {
+        Err(format!("balance cannot go negative: {balance}"))
+    } else {
+        Ok(())
+    }
+}
+
+#[crete]
+#[derive(Clone, Default)]
+struct Ledger {
+    #[crete(guard = "non_negative")]
+    balance: i64,
+    entries: u32,
+}
+
+#[test]
+fn transaction_commits_all_fields_atomically_on_ok() {
+    Ledger::default().write();
+
+    Ledger::transaction(|txn| -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        txn.set(BalanceField, 50)?;
+        txn.set(EntriesField, 1)?;
+        Ok(())
+    })
+    .unwrap();
+
+    assert_eq!(Ledger::select(BalanceField), 50);
+    assert_eq!(Ledger::select(EntriesField), 1);
+}
+
+#[test]
+fn transaction_rolls_back_when_the_guard_rejects_a_value() {
+    Ledger::default().write();
+
+    let result = Ledger::transaction(|txn| -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        txn.set(EntriesField, 99)?;
+        txn.set(BalanceField, -10)?; // guard fails, nothing should land in the store
+        Ok(())
+    });
+
+    assert!(result.is_err());
+    assert_eq!(Ledger::select(BalanceField), 0);
+    assert_eq!(Ledger::select(EntriesField), 0);
+}
+
+#[test]
+fn set_outside_a_transaction_is_also_guarded() {
+    Ledger::default().write();
+    assert!(Ledger::set(BalanceField, -1).is_err());
+    assert_eq!(Ledger::select(BalanceField), 0);
+}
@@ -0,0 +1,33 @@
+mod tuple {
+    use crete::crete;
+
+    #[crete]
+    #[derive(Clone, Default)]
+    struct Point(i32, i32);
+
+    #[test]
+    fn tuple_struct_fields_are_accessible_by_index() {
+        Point(1, 2).write();
+
+        assert_eq!(Point::select(Field0), 1);
+        assert_eq!(Point::select(Field1), 2);
+
+        Point::set(Field0, 10).unwrap();
+        assert_eq!(Point::select(Field0), 10);
+        assert_eq!(Point::select(Field1), 2);
+    }
+}
+
+mod unit {
+    use crete::crete;
+
+    #[crete]
+    #[derive(Clone, Default)]
+    struct Heartbeat;
+
+    #[test]
+    fn unit_struct_round_trips_through_the_store() {
+        Heartbeat.write();
+        let _: std::sync::Arc<Heartbeat> = Heartbeat::read();
+    }
+}
@@ -0,0 +1,22 @@
+use crete::crete;
+use futures::StreamExt;
+
+#[crete(Clone)]
+#[derive(Clone, Default)]
+struct Counter {
+    count: i32,
+}
+
+#[tokio::test]
+async fn watch_delivers_initial_value_then_dedupes_changes() {
+    let mut stream = Counter::watch(CountField);
+
+    assert_eq!(stream.next().await, Some(0));
+
+    Counter::set(CountField, 1).unwrap();
+    Counter::set(CountField, 1).unwrap(); // no actual change, should be collapsed
+    Counter::set(CountField, 2).unwrap();
+
+    assert_eq!(stream.next().await, Some(1));
+    assert_eq!(stream.next().await, Some(2));
+}